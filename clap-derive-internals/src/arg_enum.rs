@@ -0,0 +1,69 @@
+// Copyright (c) 2017 `clap-derive` Authors
+
+//! Implementation of `#[derive(ArgEnum)]`.
+//!
+//! Generates a `variants()` associated function and a `FromStr` impl for a
+//! C-like enum, so it can be used directly as the type of a `clap_app`
+//! field (see `Ty::Other` handling in `clap_app`).
+
+use syn;
+use quote::Tokens;
+
+use errors::*;
+
+pub fn impl_arg_enum(input: &syn::DeriveInput) -> Result<Tokens> {
+    let name = &input.ident;
+    let variants = match input.body {
+        syn::Body::Enum(ref variants) => variants,
+        syn::Body::Struct(_) => bail!("`#[derive(ArgEnum)]` can only be used on enums"),
+    };
+
+    for variant in variants {
+        if variant.data != syn::VariantData::Unit {
+            bail!("`#[derive(ArgEnum)]` only supports unit variants");
+        }
+    }
+
+    // `case_sensitive` is registered as a bare top-level helper attribute
+    // (`attributes(case_sensitive)` on the derive itself), so it shows up
+    // as `#[case_sensitive]` directly on the enum -- a `MetaItem::Word`,
+    // not a `NameValue` nested inside `#[clap(...)]`.
+    let case_sensitive = input.attrs.iter().any(|attr| match attr.value {
+        syn::MetaItem::Word(ref w) => w.as_ref() == "case_sensitive",
+        _ => false,
+    });
+
+    let idents: Vec<_> = variants.iter().map(|v| &v.ident).collect();
+    let names: Vec<_> = idents.iter().map(|ident| ident.as_ref().to_string()).collect();
+
+    let match_arms = idents.iter().zip(names.iter()).map(|(ident, variant_name)| {
+        if case_sensitive {
+            quote! { #variant_name => ::std::result::Result::Ok(#name::#ident), }
+        } else {
+            quote! {
+                _ if s.eq_ignore_ascii_case(#variant_name) => ::std::result::Result::Ok(#name::#ident),
+            }
+        }
+    });
+
+    Ok(quote! {
+        impl #name {
+            /// All variant names, in declaration order, suitable for use
+            /// with `Arg::possible_values`.
+            pub fn variants() -> &'static [&'static str] {
+                &[#(#names),*]
+            }
+        }
+
+        impl ::std::str::FromStr for #name {
+            type Err = String;
+
+            fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+                match s {
+                    #(#match_arms)*
+                    _ => Err(format!("valid values: {}", Self::variants().join(", "))),
+                }
+            }
+        }
+    })
+}
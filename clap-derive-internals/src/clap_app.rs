@@ -0,0 +1,582 @@
+// Copyright (c) 2017 `clap-derive` Authors
+
+//! Implementation of `#[derive(ClapApp)]`.
+
+use syn;
+use quote::Tokens;
+
+use errors::*;
+use helpers::gen_attrs;
+use {ty, sub_type, Ty, Parser, AttrSource, extract_attrs, from_attr_or_env};
+
+pub fn impl_clap_app(input: &syn::DeriveInput) -> Result<Tokens> {
+    let name = &input.ident;
+    match input.body {
+        syn::Body::Struct(syn::VariantData::Struct(ref fields)) =>
+            gen_for_struct(name, fields, &input.attrs),
+        syn::Body::Enum(ref variants) =>
+            gen_for_enum(name, variants, &input.attrs),
+        _ => bail!("`#[derive(ClapApp)]` only supports structs with named fields and enums"),
+    }
+}
+
+/// The non-`name`/`version`/`author`/`about` struct-level attributes, which
+/// have defaults pulled from `Cargo.toml` environment variables and so are
+/// handled separately from the rest.
+fn gen_app_ctor(attrs: &[syn::Attribute]) -> Tokens {
+    let parsed = extract_attrs(attrs, AttrSource::Struct).collect::<Vec<_>>();
+
+    let app_name = from_attr_or_env(&parsed, "name", "CARGO_PKG_NAME");
+    let version = from_attr_or_env(&parsed, "version", "CARGO_PKG_VERSION");
+    let author = from_attr_or_env(&parsed, "author", "CARGO_PKG_AUTHORS");
+    let about = from_attr_or_env(&parsed, "about", "CARGO_PKG_DESCRIPTION");
+
+    let rest: Vec<_> = parsed.into_iter()
+        .filter(|&(ref key, _)| match key.as_ref() {
+            "name" | "version" | "author" | "about" => false,
+            _ => true,
+        })
+        .collect();
+    let rest = gen_attrs(&rest);
+
+    quote! {
+        ::clap::App::new(#app_name)
+            .version(#version)
+            .author(#author)
+            .about(#about)
+            #rest
+    }
+}
+
+/// Opt-in `paw::ParseArgs` impl, so a `#[derive(ClapApp)]` type can be used
+/// directly as the argument of a `#[paw::main] fn main(args: T)`. Gated
+/// behind the `paw` cargo feature so crates that don't use `paw` pay
+/// nothing for it; composes with subcommand enums and flattened structs
+/// since it's generated for every derived type, not just top-level ones.
+fn gen_paw_impl(name: &syn::Ident) -> Tokens {
+    quote! {
+        #[cfg(feature = "paw")]
+        impl ::paw::ParseArgs for #name {
+            type Error = ::clap::Error;
+
+            fn parse_args() -> ::std::result::Result<Self, Self::Error> {
+                let matches = Self::clap().get_matches_safe()?;
+                Ok(Self::from_clap(&matches))
+            }
+        }
+    }
+}
+
+/// The pieces shared by any block of struct-like fields -- a top-level
+/// struct's fields, or an enum variant's fields -- regardless of where
+/// they end up being spliced into the generated code.
+struct FieldGroups {
+    /// `.arg(...)` calls for plain (non-`flatten`, non-`subcommand`) fields.
+    arg_blocks: Vec<Tokens>,
+    /// `field: expr` constructor pieces, in declaration order.
+    field_ctors: Vec<Tokens>,
+    /// `let app = Ty::augment_clap(app);` for each `flatten`ed field.
+    flatten_blocks: Vec<Tokens>,
+    /// `let app = app.subcommands(Ty::clap().subcommands);`, if one field
+    /// was marked `#[clap(subcommand)]` (without `flatten`).
+    subcommands: Tokens,
+}
+
+/// Walks a struct's (or enum variant's) fields once, sorting each into the
+/// `flatten`, `subcommand`, or plain-`Arg` bucket that `gen_for_struct` and
+/// `gen_for_enum`'s variant handling both need.
+fn gen_fields(fields: &[syn::Field]) -> FieldGroups {
+    let mut arg_blocks = Vec::new();
+    let mut field_ctors = Vec::new();
+    let mut flatten_blocks = Vec::new();
+    let mut subcmd_field = None;
+
+    for field in fields {
+        let field_name = field.ident.as_ref().expect("named field");
+        let field_attrs = extract_attrs(&field.attrs, AttrSource::Field).collect::<Vec<_>>();
+        let field_ty = &field.ty;
+
+        if has_word_attr(&field.attrs, "flatten") {
+            // A `flatten`ed field's own `augment_clap`/`from_clap` already
+            // know how to wire up their args (and, for a subcommand enum,
+            // their subcommands), so `subcommand` alongside `flatten` here
+            // needs no extra handling of its own.
+            flatten_blocks.push(quote!(let app = #field_ty::augment_clap(app);));
+            field_ctors.push(quote!(#field_name: #field_ty::from_clap(matches)));
+            continue;
+        }
+
+        if has_word_attr(&field.attrs, "subcommand") {
+            subcmd_field = Some(field_ty);
+            field_ctors.push(gen_subcmd_ctor(field_name, field_ty));
+            continue;
+        }
+
+        arg_blocks.push(gen_arg(field_name, field_ty, &field_attrs, &field.attrs));
+        field_ctors.push(gen_field_ctor(field_name, field_ty, &field_attrs, &field.attrs));
+    }
+
+    let subcommands = match subcmd_field {
+        Some(sub_ty) => quote!(let app = app.subcommands(#sub_ty::clap().subcommands);),
+        None => quote!(),
+    };
+
+    FieldGroups { arg_blocks, field_ctors, flatten_blocks, subcommands }
+}
+
+fn gen_for_struct(name: &syn::Ident, fields: &[syn::Field], attrs: &[syn::Attribute]) -> Result<Tokens> {
+    let app_ctor = gen_app_ctor(attrs);
+    let paw_impl = gen_paw_impl(name);
+
+    let FieldGroups { arg_blocks, field_ctors, flatten_blocks, subcommands } = gen_fields(fields);
+
+    Ok(quote! {
+        impl ::clap::ClapApp for #name {
+            fn augment_clap(app: ::clap::App<'static, 'static>) -> ::clap::App<'static, 'static> {
+                #(#flatten_blocks)*
+                let app = app #(.arg(#arg_blocks))*;
+                #subcommands
+                app
+            }
+
+            fn clap() -> ::clap::App<'static, 'static> {
+                Self::augment_clap(#app_ctor)
+            }
+
+            fn from_clap(matches: &::clap::ArgMatches) -> Self {
+                #name {
+                    #(#field_ctors,)*
+                }
+            }
+        }
+
+        impl #name {
+            /// Returns the fully-built `clap::App` (subcommands, flattened
+            /// args, and all), without parsing `argv`. Useful for feeding
+            /// into a shell-completion generator or dumping `--help`
+            /// programmatically.
+            pub fn into_app() -> ::clap::App<'static, 'static> {
+                Self::clap()
+            }
+
+            pub fn parse() -> Self {
+                let matches = Self::clap().get_matches();
+                Self::from_clap(&matches)
+            }
+        }
+
+        #paw_impl
+    })
+}
+
+/// Builds the field-constructor expression for a `#[clap(subcommand)]`
+/// field whose type is a nested subcommand enum (not also `flatten`ed, which
+/// instead goes through the nested type's own `from_clap`).
+fn gen_subcmd_ctor(field_name: &syn::Ident, sub_ty: &syn::Ty) -> Tokens {
+    if ty(sub_ty) == Ty::Option {
+        let inner = sub_type(sub_ty).expect("Option<T> has an inner type");
+        quote! {
+            #field_name: matches.subcommand.as_ref().map(|sc| #inner::from_subcommand(sc))
+        }
+    } else {
+        quote! {
+            #field_name: #sub_ty::from_subcommand(
+                matches.subcommand.as_ref().expect("subcommand is required")
+            )
+        }
+    }
+}
+
+fn gen_for_enum(name: &syn::Ident, variants: &[syn::Variant], attrs: &[syn::Attribute]) -> Result<Tokens> {
+    let app_ctor = gen_app_ctor(attrs);
+    let paw_impl = gen_paw_impl(name);
+
+    let mut subcommands = Vec::new();
+    let mut from_arms = Vec::new();
+
+    for variant in variants {
+        let variant_name = &variant.ident;
+        let variant_attrs = extract_attrs(&variant.attrs, AttrSource::Struct).collect::<Vec<_>>();
+        let sub_name = from_attr_or_env(&variant_attrs, "name", "");
+        let sub_name = if let syn::Lit::Str(ref s, _) = sub_name {
+            if s.is_empty() { to_kebab_case(variant_name.as_ref()) } else { s.clone() }
+        } else {
+            to_kebab_case(variant_name.as_ref())
+        };
+        let rest = gen_attrs(&variant_attrs.into_iter()
+            .filter(|&(ref key, _)| key.as_ref() != "name")
+            .collect::<Vec<_>>());
+
+        match variant.data {
+            syn::VariantData::Unit => {
+                subcommands.push(quote!(::clap::App::new(#sub_name) #rest));
+                from_arms.push(quote!(#sub_name => #name::#variant_name,));
+            }
+            syn::VariantData::Struct(ref fields) => {
+                let FieldGroups { arg_blocks, field_ctors, flatten_blocks, subcommands: nested_subcommands } =
+                    gen_fields(fields);
+                subcommands.push(quote! {
+                    {
+                        let app = ::clap::App::new(#sub_name) #rest;
+                        #(#flatten_blocks)*
+                        let app = app #(.arg(#arg_blocks))*;
+                        #nested_subcommands
+                        app
+                    }
+                });
+                from_arms.push(quote! {
+                    #sub_name => #name::#variant_name { #(#field_ctors,)* },
+                });
+            }
+            syn::VariantData::Tuple(_) => bail!("tuple variants are not supported by `#[derive(ClapApp)]`"),
+        }
+    }
+
+    Ok(quote! {
+        impl ::clap::ClapApp for #name {
+            fn augment_clap(app: ::clap::App<'static, 'static>) -> ::clap::App<'static, 'static> {
+                app.subcommands(vec![#(#subcommands),*])
+            }
+
+            fn clap() -> ::clap::App<'static, 'static> {
+                Self::augment_clap(#app_ctor)
+            }
+
+            fn from_clap(matches: &::clap::ArgMatches) -> Self {
+                #name::from_subcommand(matches.subcommand.as_ref().expect("subcommand is required"))
+            }
+        }
+
+        impl #name {
+            fn from_subcommand(sc: &(String, Box<::clap::ArgMatches>)) -> Self {
+                let matches = &*sc.1;
+                match sc.0.as_ref() {
+                    #(#from_arms)*
+                    _ => unreachable!("clap should reject unknown subcommands"),
+                }
+            }
+
+            /// Returns the fully-built `clap::App` (subcommands, flattened
+            /// args, and all), without parsing `argv`. Useful for feeding
+            /// into a shell-completion generator or dumping `--help`
+            /// programmatically.
+            pub fn into_app() -> ::clap::App<'static, 'static> {
+                Self::clap()
+            }
+
+            pub fn parse() -> Self {
+                let matches = Self::clap().get_matches();
+                Self::from_clap(&matches)
+            }
+        }
+
+        #paw_impl
+    })
+}
+
+/// Builds the `clap::Arg` for a single field, including the `validator`
+/// hookup for whichever `Parser` kind applies.
+fn gen_arg(field_name: &syn::Ident, field_ty: &syn::Ty, attrs: &[(syn::Ident, syn::Lit)], raw_attrs: &[syn::Attribute]) -> Tokens {
+    let arg_name = match attrs.iter().find(|&&(ref k, _)| k.as_ref() == "name") {
+        Some(&(_, syn::Lit::Str(ref s, _))) => s.clone(),
+        _ => to_kebab_case(field_name.as_ref()),
+    };
+    let rest = gen_attrs(&attrs.iter().cloned()
+        .filter(|&(ref k, _)| k.as_ref() != "name" && k.as_ref() != "env")
+        .collect::<Vec<_>>());
+
+    // `bool`/`u64` flags have no value for clap's own `Arg::env` to fall
+    // back to, so their env lookup happens in `gen_field_ctor` instead;
+    // every other type gets the native `.env(...)` hookup, which clap
+    // already resolves with argv > env > default_value precedence.
+    let env = match ty(field_ty) {
+        Ty::Bool | Ty::U64 => quote!(),
+        _ => match env_var(attrs) {
+            Some(var) => quote!(.env(#var)),
+            None => quote!(),
+        },
+    };
+
+    let (_, parser_fn) = gen_parser(field_ty, raw_attrs);
+
+    // An `ArgEnum`-derived field type carries its own variant set, so rather
+    // than making the user spell it out, list it for help text and
+    // validation straight off the type's generated `variants()`. The list is
+    // matched case-insensitively; case sensitivity is left to the type's own
+    // `FromStr`, which the validator runs.
+    let possible_values = if has_word_attr(raw_attrs, "arg_enum") {
+        let inner_ty = sub_type(field_ty).unwrap_or(field_ty);
+        quote!(.possible_values(#inner_ty::variants()).case_insensitive(true))
+    } else {
+        quote!()
+    };
+
+    let arity = match ty(field_ty) {
+        Ty::Bool => quote!(.takes_value(false).multiple(false)),
+        Ty::U64 => quote!(.takes_value(false).multiple(true)),
+        Ty::Option => quote!(.takes_value(true).multiple(false)),
+        Ty::Vec => quote!(.takes_value(true).multiple(true)),
+        Ty::Other => {
+            let required = !has_default_value(attrs);
+            quote!(.takes_value(true).multiple(false).required(#required))
+        }
+    };
+
+    // `parser_fn` is always `Fn(&OsStr) -> Result<T, String>`, whichever
+    // `Parser` kind produced it (see `gen_parser`), so the validator hooks
+    // into `validator_os` uniformly -- there's no `&str`-typed variant to
+    // special-case.
+    let validator = match ty(field_ty) {
+        Ty::Bool | Ty::U64 => quote!(),
+        _ => quote!(.validator_os(move |s| (#parser_fn)(s).map(|_| ()))),
+    };
+
+    quote! {
+        ::clap::Arg::with_name(#arg_name)
+            #arity
+            #validator
+            #possible_values
+            #env
+            #rest
+    }
+}
+
+/// Whether a field carries a `default_value` attribute -- a required
+/// `T: FromStr` field (`Ty::Other`) should only be `required(true)` when it
+/// doesn't have one, per the type table in the crate docs.
+fn has_default_value(attrs: &[(syn::Ident, syn::Lit)]) -> bool {
+    attrs.iter().any(|&(ref k, _)| k.as_ref() == "default_value")
+}
+
+/// Reads the `env = "VAR"` field attribute, if any.
+fn env_var(attrs: &[(syn::Ident, syn::Lit)]) -> Option<String> {
+    attrs.iter().find(|&&(ref k, _)| k.as_ref() == "env").and_then(|&(_, ref v)| match *v {
+        syn::Lit::Str(ref s, _) => Some(s.clone()),
+        _ => panic!("`env` must be a string, e.g. `env = \"MY_VAR\"`"),
+    })
+}
+
+/// Builds the expression that extracts and converts a single field's value
+/// out of `&ArgMatches`, reusing the same `Parser` chosen by `gen_parser`.
+fn gen_field_ctor(field_name: &syn::Ident, field_ty: &syn::Ty, attrs: &[(syn::Ident, syn::Lit)], raw_attrs: &[syn::Attribute]) -> Tokens {
+    let arg_name = match attrs.iter().find(|&&(ref k, _)| k.as_ref() == "name") {
+        Some(&(_, syn::Lit::Str(ref s, _))) => s.clone(),
+        _ => to_kebab_case(field_name.as_ref()),
+    };
+    let (_, parser_fn) = gen_parser(field_ty, raw_attrs);
+    let env = env_var(attrs);
+
+    match ty(field_ty) {
+        Ty::Bool => match env {
+            Some(var) => quote! {
+                #field_name: matches.is_present(#arg_name)
+                    || ::std::env::var_os(#var).map_or(false, |v| !v.is_empty())
+            },
+            None => quote!(#field_name: matches.is_present(#arg_name)),
+        },
+        Ty::U64 => match env {
+            Some(var) => quote! {
+                #field_name: {
+                    let count = matches.occurrences_of(#arg_name);
+                    if count > 0 { count } else {
+                        ::std::env::var_os(#var).map_or(0, |v| if v.is_empty() { 0 } else { 1 })
+                    }
+                }
+            },
+            None => quote!(#field_name: matches.occurrences_of(#arg_name)),
+        },
+        Ty::Option => quote! {
+            #field_name: matches.value_of_os(#arg_name).map(|s| (#parser_fn)(s).unwrap())
+        },
+        Ty::Vec => quote! {
+            #field_name: matches.values_of_os(#arg_name)
+                .map(|vs| vs.map(|s| (#parser_fn)(s).unwrap()).collect())
+                .unwrap_or_else(Vec::new)
+        },
+        Ty::Other => quote! {
+            #field_name: (#parser_fn)(matches.value_of_os(#arg_name).expect("required argument")).unwrap()
+        },
+    }
+}
+
+/// Picks the `Parser` for a field: an explicit `#[clap(parse(...))]`
+/// attribute always wins; otherwise a default is chosen automatically.
+///
+/// Since a proc-macro cannot query trait impls, the automatic case is
+/// resolved at the field's own monomorphization site via autoref
+/// specialization: `(&&&&ParserSelector::<T>::new()).parser()` picks the
+/// first of `FromStr`, `From<&str>`, `From<&OsStr>`, and `TryFrom<&OsStr>`
+/// (in that order) that's implemented for `T`, since method lookup tries the
+/// impl on the most-referenced selector type first. Every branch returns a boxed
+/// closure of the same shape, so the rest of the generated code (the
+/// `validator`/`from_clap` wiring above) never needs to know which one
+/// fired.
+fn gen_parser(field_ty: &syn::Ty, raw_attrs: &[syn::Attribute]) -> (Parser, Tokens) {
+    if let Some(result) = parse_attribute(field_ty, raw_attrs) {
+        return result;
+    }
+
+    if has_word_attr(raw_attrs, "arg_enum") {
+        let inner_ty = sub_type(field_ty).unwrap_or(field_ty);
+        return (Parser::TryFromStr, quote! {
+            |s: &::std::ffi::OsStr| -> Result<#inner_ty, String> {
+                let s = s.to_str().ok_or_else(|| "invalid UTF-8".to_string())?;
+                ::std::str::FromStr::from_str(s)
+            }
+        });
+    }
+
+    let inner_ty = sub_type(field_ty).unwrap_or(field_ty);
+    let selector = quote! {
+        {
+            #[allow(non_snake_case)]
+            #[doc(hidden)]
+            struct ParserSelector<T>(::std::marker::PhantomData<T>);
+            impl<T> ParserSelector<T> {
+                fn new() -> Self { ParserSelector(::std::marker::PhantomData) }
+            }
+
+            trait ViaTryFromOsStr<T> {
+                fn parser(&self) -> Box<Fn(&::std::ffi::OsStr) -> Result<T, String>>;
+            }
+            impl<T> ViaTryFromOsStr<T> for ParserSelector<T>
+            where T: for<'r> ::std::convert::TryFrom<&'r ::std::ffi::OsStr>,
+                  for<'r> <T as ::std::convert::TryFrom<&'r ::std::ffi::OsStr>>::Error: ::std::fmt::Debug,
+            {
+                fn parser(&self) -> Box<Fn(&::std::ffi::OsStr) -> Result<T, String>> {
+                    Box::new(|s| ::std::convert::TryFrom::try_from(s).map_err(|e| format!("{:?}", e)))
+                }
+            }
+
+            trait ViaFromOsStr<T> {
+                fn parser(&self) -> Box<Fn(&::std::ffi::OsStr) -> Result<T, String>>;
+            }
+            impl<'a, T> ViaFromOsStr<T> for &'a ParserSelector<T>
+            where T: for<'r> ::std::convert::From<&'r ::std::ffi::OsStr>,
+            {
+                fn parser(&self) -> Box<Fn(&::std::ffi::OsStr) -> Result<T, String>> {
+                    Box::new(|s| Ok(::std::convert::From::from(s)))
+                }
+            }
+
+            trait ViaTryFromStr<T> {
+                fn parser(&self) -> Box<Fn(&::std::ffi::OsStr) -> Result<T, String>>;
+            }
+            impl<'a, T> ViaTryFromStr<T> for &'a &'a &'a ParserSelector<T>
+            where T: ::std::str::FromStr,
+                  <T as ::std::str::FromStr>::Err: ::std::fmt::Display,
+            {
+                fn parser(&self) -> Box<Fn(&::std::ffi::OsStr) -> Result<T, String>> {
+                    Box::new(|s| {
+                        let s = s.to_str().ok_or_else(|| "invalid UTF-8".to_string())?;
+                        ::std::str::FromStr::from_str(s).map_err(|e| e.to_string())
+                    })
+                }
+            }
+
+            trait ViaFromStr<T> {
+                fn parser(&self) -> Box<Fn(&::std::ffi::OsStr) -> Result<T, String>>;
+            }
+            impl<'a, T> ViaFromStr<T> for &'a &'a ParserSelector<T>
+            where T: for<'r> ::std::convert::From<&'r str>,
+            {
+                fn parser(&self) -> Box<Fn(&::std::ffi::OsStr) -> Result<T, String>> {
+                    Box::new(|s| {
+                        let s = s.to_str().ok_or_else(|| "invalid UTF-8".to_string())?;
+                        Ok(::std::convert::From::from(s))
+                    })
+                }
+            }
+
+            (&&&&ParserSelector::<#inner_ty>::new()).parser()
+        }
+    };
+
+    (Parser::TryFromStr, selector)
+}
+
+/// Reads an explicit `#[clap(parse(...))]` attribute, if any.
+///
+/// `parse(...)` nests a second `MetaItem::List` inside the field's
+/// `#[clap(...)]` list (e.g. `parse(try_from_str = "parse_hex")` is a
+/// `List(parse, [NameValue(try_from_str, "parse_hex")])`, and bare
+/// `parse(from_os_str)` is a `List(parse, [Word(from_os_str)])`) -- it does
+/// not show up as a flat `(Ident, Lit)` pair, so this reads straight off the
+/// raw attributes rather than going through `extract_attrs`.
+fn parse_attribute(field_ty: &syn::Ty, raw_attrs: &[syn::Attribute]) -> Option<(Parser, Tokens)> {
+    let inner_ty = sub_type(field_ty).unwrap_or(field_ty);
+    let nested = raw_attrs.iter()
+        .filter_map(|attr| match attr.value {
+            syn::MetaItem::List(ref i, ref v) if i.as_ref() == "clap" => Some(v),
+            _ => None,
+        })
+        .flat_map(|v| v.iter())
+        .filter_map(|mi| match *mi {
+            syn::NestedMetaItem::MetaItem(syn::MetaItem::List(ref i, ref spec)) if i.as_ref() == "parse" => Some(spec),
+            _ => None,
+        })
+        .next()?;
+
+    let (kind, func) = match nested.first() {
+        Some(&syn::NestedMetaItem::MetaItem(syn::MetaItem::Word(ref kind))) =>
+            (kind.as_ref().to_string(), None),
+        Some(&syn::NestedMetaItem::MetaItem(syn::MetaItem::NameValue(ref kind, syn::Lit::Str(ref func, _)))) =>
+            (kind.as_ref().to_string(), Some(func.clone())),
+        _ => panic!("`parse(...)` must contain a single `kind` or `kind = \"fn\"`, e.g. `parse(try_from_str = \"my_fn\")`"),
+    };
+
+    Some(match kind.as_str() {
+        "from_str" => {
+            let func: Tokens = func.map(|f| f.parse().unwrap()).unwrap_or_else(|| quote!(::std::convert::From::from));
+            (Parser::FromStr, quote!(|s: &::std::ffi::OsStr| -> Result<#inner_ty, String> {
+                let s = s.to_str().ok_or_else(|| "invalid UTF-8".to_string())?;
+                Ok((#func)(s))
+            }))
+        }
+        "try_from_str" => {
+            let func: Tokens = func.map(|f| f.parse().unwrap()).unwrap_or_else(|| quote!(::std::str::FromStr::from_str));
+            (Parser::TryFromStr, quote!(|s: &::std::ffi::OsStr| -> Result<#inner_ty, String> {
+                let s = s.to_str().ok_or_else(|| "invalid UTF-8".to_string())?;
+                (#func)(s).map_err(|e| e.to_string())
+            }))
+        }
+        "from_os_str" => {
+            let func: Tokens = func.map(|f| f.parse().unwrap()).unwrap_or_else(|| quote!(::std::convert::From::from));
+            (Parser::FromOsStr, quote!(|s: &::std::ffi::OsStr| -> Result<#inner_ty, String> { Ok((#func)(s)) }))
+        }
+        "try_from_os_str" => {
+            let func: Tokens = func.expect("try_from_os_str has no default function").parse().unwrap();
+            (Parser::TryFromOsStr, quote!(|s: &::std::ffi::OsStr| -> Result<#inner_ty, String> {
+                (#func)(s).map_err(|e| e.to_string_lossy().into_owned())
+            }))
+        }
+        _ => panic!("unknown parser kind `{}`, expected one of from_str, try_from_str, from_os_str, try_from_os_str", kind),
+    })
+}
+
+fn has_word_attr(attrs: &[syn::Attribute], word: &str) -> bool {
+    attrs.iter().any(|attr| match attr.value {
+        syn::MetaItem::List(ref i, ref v) if i.as_ref() == "clap" => {
+            v.iter().any(|mi| match *mi {
+                syn::NestedMetaItem::MetaItem(syn::MetaItem::Word(ref w)) => w.as_ref() == word,
+                _ => false,
+            })
+        }
+        _ => false,
+    })
+}
+
+fn to_kebab_case(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for (i, c) in s.chars().enumerate() {
+        if c == '_' {
+            out.push('-');
+        } else if c.is_uppercase() {
+            if i != 0 { out.push('-'); }
+            out.extend(c.to_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
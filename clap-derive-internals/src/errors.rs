@@ -0,0 +1,20 @@
+// Copyright (c) 2017 `clap-derive` Authors
+
+//! Error types used while expanding the `ClapApp` and `ArgEnum` derives.
+
+error_chain! {
+    errors {
+        /// The input to the derive could not be parsed as a valid struct/enum
+        /// definition.
+        ParseError(e: String) {
+            description("parse error")
+            display("{}", e)
+        }
+        /// The tokens generated by the derive could not be re-lexed into a
+        /// `proc_macro::TokenStream`.
+        ProcLexError(e: ::proc_macro::LexError) {
+            description("lex error")
+            display("{:?}", e)
+        }
+    }
+}
@@ -0,0 +1,32 @@
+// Copyright (c) 2017 `clap-derive` Authors
+
+//! Small helpers shared by the `clap_app` and `arg_enum` expansions.
+
+use syn;
+use quote::Tokens;
+
+/// Turns a list of `(key, value)` attributes into a chain of builder method
+/// calls, e.g. `[(short, "d")]` becomes `.short("d")`.
+///
+/// A key ending in `_raw` (e.g. `aliases_raw`) is stripped of the suffix and
+/// its value is parsed as a Rust expression instead of being quoted as a
+/// string literal, so `aliases_raw = "&[\"alias\"]"` becomes
+/// `.aliases(&["alias"])`.
+pub fn gen_attrs(attrs: &[(syn::Ident, syn::Lit)]) -> Tokens {
+    let calls = attrs.iter().map(|&(ref key, ref value)| gen_attr(key, value));
+    quote!(#(#calls)*)
+}
+
+fn gen_attr(key: &syn::Ident, value: &syn::Lit) -> Tokens {
+    let key_str = key.as_ref();
+    if key_str.ends_with("_raw") {
+        let method = syn::Ident::from(&key_str[..key_str.len() - "_raw".len()]);
+        let raw: Tokens = match *value {
+            syn::Lit::Str(ref s, _) => s.parse().expect("*_raw attribute is not a valid expression"),
+            _ => panic!("*_raw attributes must be string literals"),
+        };
+        quote!(.#method(#raw))
+    } else {
+        quote!(.#key(#value))
+    }
+}
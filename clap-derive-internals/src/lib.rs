@@ -194,6 +194,18 @@
 //! }
 //! ```
 //!
+//! Besides `parse`, the derive also generates `into_app()`, which returns
+//! the fully-built `clap::App` -- subcommands, flattened args, and all --
+//! without parsing `argv`. This is what you want if you need to feed the
+//! `App` into a shell-completion generator or otherwise inspect it
+//! programmatically before (or instead of) parsing.
+//!
+//! Fields of any `#[derive(ClapApp)]` type, not just subcommand enums, can
+//! also be composed into the parent with `#[clap(flatten)]`, which splices
+//! the nested type's args into the parent `App` and builds it from the same
+//! `ArgMatches`. This is handy for pulling shared option groups (e.g.
+//! verbosity, global config) out into a reusable struct.
+//!
 //! Marking a field with `clap(subcommand)` will add the subcommands of the
 //! designated enum to the current `clap::App`. The designated enum *must* also
 //! be derived `ClapApp`. So the above example would take the following
@@ -224,8 +236,40 @@
 //! }
 //! ```
 //!
+//! ## Environment variable fallback
+//!
+//! A field can fall back to an environment variable when the corresponding
+//! flag is absent from argv, using `#[clap(env = "MY_VAR")]`. The usual
+//! precedence applies: an explicit command line value wins, then the
+//! environment variable, then `default_value`. For `bool` and `u64` fields
+//! (which don't take a value), the variable counts as "set" only when it's
+//! present and non-empty.
+//!
+//! ## `paw` integration
+//!
+//! With the optional `paw` cargo feature enabled, every `#[derive(ClapApp)]`
+//! type also gets an impl of `paw::ParseArgs`, so it can be used directly as
+//! the argument of a `#[paw::main]`-annotated `fn main`:
+//!
+//! ```ignore
+//! #[paw::main]
+//! fn main(args: MyApp) {
+//!     // `args` has already been parsed from `std::env::args_os()`.
+//! }
+//! ```
+//!
+//! Crates that don't enable the feature don't pay for it -- the impl is
+//! emitted under `#[cfg(feature = "paw")]`, which is checked in the crate
+//! using the derive, so without that feature it is compiled out.
+//!
 //! ## Custom string parsers
 //!
+//! By default, a field's parser is picked for you: `clap_app` prefers
+//! `FromStr`, but falls back to `From<&str>`, `From<&OsStr>`, or
+//! `TryFrom<&OsStr>` when those are the only conversions available for the
+//! field's type. You don't need to write `parse(...)` at all unless you
+//! want to override this.
+//!
 //! If the field type does not have a `FromStr` implementation, or you would
 //! like to provide a custom parsing scheme other than `FromStr`, you may
 //! provide a custom string parser using `parse(...)` like this:
@@ -268,6 +312,26 @@
 //! In the `try_from_*` variants, the function will run twice on valid input:
 //! once to validate, and once to parse. Hence, make sure the function is
 //! side-effect-free.
+//!
+//! ## `ArgEnum` fields
+//!
+//! A field whose type derives `ArgEnum` can be marked `#[clap(arg_enum)]`,
+//! since `clap_app` can't otherwise tell that the type came from that
+//! derive. This wires `.possible_values(Type::variants())` onto the `Arg`
+//! and parses with the type's generated `FromStr` impl (which already
+//! honors `#[case_sensitive]` on the `ArgEnum` derive), so the variants get
+//! validation and nicer help text without hand-listing them:
+//!
+//! ```ignore
+//! #[derive(ArgEnum)]
+//! enum Flavor { Glaze, Powder }
+//!
+//! #[derive(ClapApp)]
+//! struct Cookie {
+//!     #[clap(arg_enum)]
+//!     flavor: Flavor,
+//! }
+//! ```
 
 
 #![recursion_limit="256"]
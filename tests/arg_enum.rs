@@ -0,0 +1,62 @@
+// Copyright 2018 Guillaume Pinot (@TeXitoi) <texitoi@texitoi.eu>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use clap::{ArgEnum, ClapApp};
+
+#[derive(ArgEnum, Debug, PartialEq)]
+enum Flavor {
+    Glaze,
+    Powder,
+}
+
+#[derive(ClapApp)]
+#[clap(name = "cookie")]
+struct Cookie {
+    #[clap(arg_enum)]
+    flavor: Flavor,
+}
+
+#[test]
+fn arg_enum_field_parses_a_valid_variant() {
+    let app = Cookie::from_clap(&Cookie::clap().get_matches_from(&["cookie", "Glaze"]));
+    assert_eq!(app.flavor, Flavor::Glaze);
+}
+
+#[test]
+fn arg_enum_field_rejects_values_outside_possible_values() {
+    let result = Cookie::clap().get_matches_from_safe(&["cookie", "Sprinkles"]);
+    assert!(result.is_err());
+}
+
+#[derive(ArgEnum, Debug, PartialEq)]
+#[case_sensitive]
+enum StrictFlavor {
+    Glaze,
+    Powder,
+}
+
+#[derive(ClapApp)]
+#[clap(name = "strict-cookie")]
+struct StrictCookie {
+    #[clap(arg_enum)]
+    flavor: StrictFlavor,
+}
+
+#[test]
+fn case_sensitive_arg_enum_rejects_a_differently_cased_match() {
+    let result = StrictCookie::clap().get_matches_from_safe(&["strict-cookie", "glaze"]);
+    assert!(result.is_err());
+}
+
+#[test]
+fn case_sensitive_arg_enum_accepts_the_exact_case() {
+    let app = StrictCookie::from_clap(&StrictCookie::clap().get_matches_from(&[
+        "strict-cookie", "Glaze",
+    ]));
+    assert_eq!(app.flavor, StrictFlavor::Glaze);
+}
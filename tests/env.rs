@@ -0,0 +1,51 @@
+// Copyright 2018 Guillaume Pinot (@TeXitoi) <texitoi@texitoi.eu>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use clap::ClapApp;
+
+#[derive(ClapApp)]
+#[clap(name = "env-app")]
+struct EnvApp {
+    #[clap(long = "name", env = "CLAP_DERIVE_TEST_NAME", default_value = "fallback")]
+    name: String,
+    #[clap(long = "verbose", env = "CLAP_DERIVE_TEST_VERBOSE")]
+    verbose: bool,
+}
+
+#[test]
+fn argv_wins_over_env() {
+    std::env::set_var("CLAP_DERIVE_TEST_NAME", "from-env");
+    let app = EnvApp::from_clap(&EnvApp::clap().get_matches_from(&[
+        "env-app", "--name", "from-argv",
+    ]));
+    std::env::remove_var("CLAP_DERIVE_TEST_NAME");
+    assert_eq!(app.name, "from-argv");
+}
+
+#[test]
+fn env_wins_over_default_value() {
+    std::env::set_var("CLAP_DERIVE_TEST_NAME", "from-env");
+    let app = EnvApp::from_clap(&EnvApp::clap().get_matches_from(&["env-app"]));
+    std::env::remove_var("CLAP_DERIVE_TEST_NAME");
+    assert_eq!(app.name, "from-env");
+}
+
+#[test]
+fn default_value_is_used_when_env_is_unset() {
+    std::env::remove_var("CLAP_DERIVE_TEST_NAME");
+    let app = EnvApp::from_clap(&EnvApp::clap().get_matches_from(&["env-app"]));
+    assert_eq!(app.name, "fallback");
+}
+
+#[test]
+fn bool_flag_is_set_from_a_present_env_var() {
+    std::env::set_var("CLAP_DERIVE_TEST_VERBOSE", "1");
+    let app = EnvApp::from_clap(&EnvApp::clap().get_matches_from(&["env-app"]));
+    std::env::remove_var("CLAP_DERIVE_TEST_VERBOSE");
+    assert!(app.verbose);
+}
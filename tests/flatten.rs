@@ -0,0 +1,41 @@
+// Copyright 2018 Guillaume Pinot (@TeXitoi) <texitoi@texitoi.eu>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use clap::ClapApp;
+
+#[derive(ClapApp)]
+struct Verbosity {
+    #[clap(long = "verbose")]
+    verbose: bool,
+    #[clap(long = "quiet")]
+    quiet: bool,
+}
+
+#[derive(ClapApp)]
+#[clap(name = "app")]
+struct App {
+    #[clap(flatten)]
+    verbosity: Verbosity,
+    input: String,
+}
+
+#[test]
+fn flattened_fields_are_parsed_into_the_nested_struct() {
+    let app = App::from_clap(&App::clap().get_matches_from(&[
+        "app", "--verbose", "in.txt",
+    ]));
+    assert!(app.verbosity.verbose);
+    assert!(!app.verbosity.quiet);
+    assert_eq!(app.input, "in.txt");
+}
+
+#[test]
+fn flattened_args_show_up_on_the_parent_app() {
+    let matches = App::clap().get_matches_from_safe(&["app", "--quiet", "in.txt"]);
+    assert!(matches.is_ok());
+}
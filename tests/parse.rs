@@ -0,0 +1,42 @@
+// Copyright 2018 Guillaume Pinot (@TeXitoi) <texitoi@texitoi.eu>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::num::ParseIntError;
+use std::path::PathBuf;
+
+use clap::ClapApp;
+
+fn parse_hex(src: &str) -> Result<u32, ParseIntError> {
+    u32::from_str_radix(src, 16)
+}
+
+#[derive(ClapApp)]
+#[clap(name = "hex-reader")]
+struct HexReader {
+    #[clap(short = "n", parse(try_from_str = "parse_hex"))]
+    number: u32,
+    #[clap(short = "o", parse(from_os_str))]
+    output: PathBuf,
+}
+
+#[test]
+fn explicit_parser_overrides_autodetect() {
+    let app = HexReader::from_clap(&HexReader::clap().get_matches_from(&[
+        "hex-reader", "-n", "ff", "-o", "out.txt",
+    ]));
+    assert_eq!(app.number, 255);
+    assert_eq!(app.output, PathBuf::from("out.txt"));
+}
+
+#[test]
+fn explicit_parser_rejects_input_its_function_would_reject() {
+    let result = HexReader::clap().get_matches_from_safe(&[
+        "hex-reader", "-n", "not-hex", "-o", "out.txt",
+    ]);
+    assert!(result.is_err());
+}
@@ -0,0 +1,29 @@
+// Copyright 2018 Guillaume Pinot (@TeXitoi) <texitoi@texitoi.eu>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use clap::ClapApp;
+
+#[derive(ClapApp)]
+struct Verbosity {
+    #[clap(long = "verbose")]
+    verbose: bool,
+
+    #[clap(long = "quiet")]
+    quiet: bool,
+}
+
+#[derive(ClapApp)]
+#[clap(name = "app")]
+struct App {
+    #[clap(flatten)]
+    verbosity: Verbosity,
+
+    input: String,
+}
+
+fn main() {}